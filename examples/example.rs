@@ -19,11 +19,11 @@ fn main() {
         "Eye lyked it alot.".to_string(),
     );
 
-    book_reviews.remove("The Adventures of Sherlock Holmes".to_string());
+    book_reviews.remove("The Adventures of Sherlock Holmes");
 
     let to_find = ["Pride and Prejudice", "Alice's Adventure in Wonderland"];
     for &book in &to_find {
-        match book_reviews.get(book.to_string()) {
+        match book_reviews.get(book) {
             Some(review) => println!("{book}: {review}"),
             None => println!("{book} is unreviewed.")
         }
@@ -33,7 +33,7 @@ fn main() {
         println!("{book}: \"{review}\"");
     }
 
-    if !book_reviews.contains_key("Les Misérables".to_string()) {
+    if !book_reviews.contains_key("Les Misérables") {
         println!("We've got {} reviews, but Les Misérables ain't one.",
                  book_reviews.len());
     }
@@ -0,0 +1,770 @@
+use std::hash::{BuildHasher, Hash};
+use std::borrow::Borrow;
+use std::collections::hash_map::RandomState;
+use std::collections::TryReserveError;
+use std::mem;
+
+/// A table slot: the full cached hash alongside the key/value pair it belongs to,
+/// or `None` if the slot is empty.
+type Slot<K, V> = Option<(u64, K, V)>;
+
+/// Robin Hood open-addressing hash map, following the scheme described by Celis and
+/// Goossaert: entries probe linearly from their ideal slot, and an insert steals the
+/// slot of any entry that has travelled a shorter distance than itself.
+#[derive(Debug)]
+pub struct HashMap<K, V, S = RandomState> {
+    buckets: Vec<Slot<K, V>>,
+    items: usize,
+    hash_builder: S,
+}
+
+pub struct HashMapIter<'a, K, V> {
+    buckets: &'a [Slot<K, V>],
+    pos: usize,
+}
+
+pub struct HashMapIterMut<'a, K, V> {
+    buckets: &'a mut [Slot<K, V>],
+}
+
+pub struct HashMapIntoIter<K, V> {
+    buckets: std::vec::IntoIter<Slot<K, V>>,
+}
+
+pub struct HashMapKeys<'a, K, V> {
+    inner: HashMapIter<'a, K, V>,
+}
+
+pub struct HashMapValues<'a, K, V> {
+    inner: HashMapIter<'a, K, V>,
+}
+
+pub struct HashMapValuesMut<'a, K, V> {
+    inner: HashMapIterMut<'a, K, V>,
+}
+
+/// A view into a single entry in a map, which may either be vacant or occupied.
+///
+/// This enum is constructed from the [`entry`](HashMap::entry) method on [`HashMap`].
+pub enum Entry<'a, K, V, S> {
+    Occupied(OccupiedEntry<'a, K, V, S>),
+    Vacant(VacantEntry<'a, K, V, S>),
+}
+
+/// A view into an occupied entry in a [`HashMap`]. It is part of the [`Entry`] enum.
+pub struct OccupiedEntry<'a, K, V, S> {
+    map: &'a mut HashMap<K, V, S>,
+    index: usize,
+}
+
+/// A view into a vacant entry in a [`HashMap`]. It is part of the [`Entry`] enum.
+pub struct VacantEntry<'a, K, V, S> {
+    map: &'a mut HashMap<K, V, S>,
+    key: K,
+    hash: u64,
+}
+
+impl<'a, K, V, S> Entry<'a, K, V, S>
+where
+    K: Hash + Eq,
+    S: BuildHasher,
+{
+    /// Ensures a value is in the entry by inserting the default if empty, and returns
+    /// a mutable reference to the value in the entry.
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default),
+        }
+    }
+
+    /// Ensures a value is in the entry by inserting the result of the default function
+    /// if empty, and returns a mutable reference to the value in the entry.
+    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default()),
+        }
+    }
+
+    /// Ensures a value is in the entry by inserting the default value if empty, and
+    /// returns a mutable reference to the value in the entry.
+    pub fn or_default(self) -> &'a mut V
+    where
+        V: Default,
+    {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(V::default()),
+        }
+    }
+
+    /// Provides in-place mutable access to an occupied entry before any potential
+    /// inserts into the map.
+    pub fn and_modify<F: FnOnce(&mut V)>(self, f: F) -> Self {
+        match self {
+            Entry::Occupied(mut entry) => {
+                f(entry.get_mut());
+                Entry::Occupied(entry)
+            }
+            Entry::Vacant(entry) => Entry::Vacant(entry),
+        }
+    }
+}
+
+impl<'a, K, V, S> OccupiedEntry<'a, K, V, S> {
+    fn get_mut(&mut self) -> &mut V {
+        &mut self.map.buckets[self.index].as_mut().unwrap().2
+    }
+
+    fn into_mut(self) -> &'a mut V {
+        &mut self.map.buckets[self.index].as_mut().unwrap().2
+    }
+}
+
+impl<'a, K, V, S> VacantEntry<'a, K, V, S>
+where
+    K: Hash + Eq,
+    S: BuildHasher,
+{
+    fn insert(self, value: V) -> &'a mut V {
+        if self.map.buckets.is_empty() || self.map.items > 3 * self.map.buckets.len() / 4 {
+            self.map.resize();
+        }
+
+        let (_, index) = robin_hood_insert(&mut self.map.buckets, self.hash, self.key, value);
+        self.map.items += 1;
+        &mut self.map.buckets[index].as_mut().unwrap().2
+    }
+}
+
+/// Inserts `(hash, key, value)` into `buckets` using Robin Hood displacement,
+/// returning the previous value if `key` was already present along with the slot
+/// the newly-inserted `(key, value)` ended up in.
+fn robin_hood_insert<K: Eq, V>(
+    buckets: &mut [Slot<K, V>],
+    hash: u64,
+    mut key: K,
+    mut value: V,
+) -> (Option<V>, usize) {
+    let cap = buckets.len();
+    let mut idx = (hash % cap as u64) as usize;
+    let mut dist = 0usize;
+    let mut cur_hash = hash;
+    let mut placed_at = None;
+
+    loop {
+        if buckets[idx].is_none() {
+            buckets[idx] = Some((cur_hash, key, value));
+            return (None, placed_at.unwrap_or(idx));
+        }
+
+        let slot = buckets[idx].as_mut().unwrap();
+        if slot.0 == cur_hash && slot.1 == key {
+            return (Some(mem::replace(&mut slot.2, value)), idx);
+        }
+
+        let slot_ideal = (slot.0 % cap as u64) as usize;
+        let slot_dist = (idx + cap - slot_ideal) % cap;
+        if slot_dist < dist {
+            // Steal from the rich: the poorer newcomer takes this slot, and the
+            // richer occupant keeps probing in its place.
+            mem::swap(&mut slot.0, &mut cur_hash);
+            mem::swap(&mut slot.1, &mut key);
+            mem::swap(&mut slot.2, &mut value);
+            placed_at.get_or_insert(idx);
+            dist = slot_dist;
+        }
+
+        idx = (idx + 1) % cap;
+        dist += 1;
+    }
+}
+
+/// Returns the smallest power-of-two bucket count under which `capacity` items fit
+/// the 3/4 load factor, or `usize::MAX` if no such count fits in a `usize`.
+fn buckets_for_capacity(capacity: usize) -> usize {
+    if capacity == 0 {
+        return 0;
+    }
+    let mut n: usize = 1;
+    while (n as u128) * 3 / 4 < capacity as u128 {
+        match n.checked_mul(2) {
+            Some(next) => n = next,
+            None => return usize::MAX,
+        }
+    }
+    n
+}
+
+impl<K, V> HashMap<K, V, RandomState>
+{
+    pub fn new() -> Self {
+        HashMap {
+            buckets: Vec::new(),
+            items: 0,
+            hash_builder: RandomState::new(),
+        }
+    }
+
+    /// Creates an empty `HashMap` with at least the specified capacity.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity_and_hasher(capacity, RandomState::new())
+    }
+}
+
+impl<K, V> Default for HashMap<K, V, RandomState> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, V, S> HashMap<K, V, S> {
+    /// Creates an empty `HashMap` which will use the given hash builder to hash keys.
+    pub fn with_hasher(hash_builder: S) -> Self {
+        HashMap {
+            buckets: Vec::new(),
+            items: 0,
+            hash_builder,
+        }
+    }
+
+    /// Creates an empty `HashMap` with at least the specified capacity, using the
+    /// given hash builder to hash keys.
+    pub fn with_capacity_and_hasher(capacity: usize, hash_builder: S) -> Self {
+        let target_size = buckets_for_capacity(capacity);
+        let mut buckets = Vec::with_capacity(target_size);
+        buckets.extend((0..target_size).map(|_| None));
+        HashMap {
+            buckets,
+            items: 0,
+            hash_builder,
+        }
+    }
+
+    /// Returns a lower bound on the number of elements the map can hold without
+    /// reallocating.
+    pub fn capacity(&self) -> usize {
+        self.buckets.len() * 3 / 4
+    }
+
+    pub fn len(&self) -> usize {
+        self.items
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items == 0
+    }
+
+    /// Returns a mutable iterator over `(&K, &mut V)` pairs.
+    pub fn iter_mut(&mut self) -> HashMapIterMut<'_, K, V> {
+        HashMapIterMut { buckets: &mut self.buckets }
+    }
+
+    /// Returns an iterator over the keys.
+    pub fn keys(&self) -> HashMapKeys<'_, K, V> {
+        HashMapKeys { inner: self.into_iter() }
+    }
+
+    /// Returns an iterator over the values.
+    pub fn values(&self) -> HashMapValues<'_, K, V> {
+        HashMapValues { inner: self.into_iter() }
+    }
+
+    /// Returns a mutable iterator over the values.
+    pub fn values_mut(&mut self) -> HashMapValuesMut<'_, K, V> {
+        HashMapValuesMut { inner: self.iter_mut() }
+    }
+
+    /// Removes all entries, returning them as an iterator that owns them.
+    pub fn drain(&mut self) -> HashMapIntoIter<K, V> {
+        let buckets = mem::take(&mut self.buckets);
+        self.items = 0;
+        HashMapIntoIter { buckets: buckets.into_iter() }
+    }
+}
+
+impl<K,V,S> HashMap<K,V,S>  where
+    K: Hash + Eq,
+    S: BuildHasher,
+{
+    fn hash_of<Q>(&self, key: &Q) -> u64
+    where
+        Q: Hash + ?Sized,
+    {
+        self.hash_builder.hash_one(key)
+    }
+
+    /// Returns the slot holding `key`, or `None` if it isn't present. Probing stops
+    /// as soon as it meets a slot whose own probe distance is shorter than the
+    /// distance already travelled, since Robin Hood insertion guarantees `key` would
+    /// have displaced that slot had it been inserted.
+    fn find_index<Q>(&self, hash: u64, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        if self.buckets.is_empty() {
+            return None;
+        }
+        let cap = self.buckets.len();
+        let mut idx = (hash % cap as u64) as usize;
+        let mut dist = 0usize;
+        loop {
+            let (ehash, ekey, _) = self.buckets[idx].as_ref()?;
+            if *ehash == hash && ekey.borrow() == key {
+                return Some(idx);
+            }
+            let eideal = (*ehash % cap as u64) as usize;
+            let edist = (idx + cap - eideal) % cap;
+            if edist < dist {
+                return None;
+            }
+            idx = (idx + 1) % cap;
+            dist += 1;
+        }
+    }
+
+    /// Gets the given key's corresponding entry in the map for in-place manipulation,
+    /// computing the key's hash only once even when the entry is vacant.
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V, S> {
+        let hash = self.hash_of(&key);
+        if let Some(index) = self.find_index(hash, &key) {
+            return Entry::Occupied(OccupiedEntry { map: self, index });
+        }
+        Entry::Vacant(VacantEntry { map: self, key, hash })
+    }
+
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        if self.buckets.is_empty() || self.items > 3 * self.buckets.len() / 4 {
+            self.resize();
+        }
+
+        let hash = self.hash_of(&key);
+        let (old, _) = robin_hood_insert(&mut self.buckets, hash, key, value);
+        if old.is_none() {
+            self.items += 1;
+        }
+        old
+    }
+
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let hash = self.hash_of(key);
+        self.find_index(hash, key).is_some()
+    }
+
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let hash = self.hash_of(key);
+        let index = self.find_index(hash, key)?;
+        self.buckets[index].as_ref().map(|(_, _, v)| v)
+    }
+
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let hash = self.hash_of(key);
+        let index = self.find_index(hash, key)?;
+        let (_, _, value) = self.buckets[index].take().unwrap();
+        self.items -= 1;
+        self.backward_shift(index);
+        Some(value)
+    }
+
+    /// Backward-shift deletion: walks forward from the freshly-emptied `prev` slot,
+    /// pulling each following entry back one slot until an empty slot is hit or an
+    /// entry is found that is already sitting in its ideal slot (probe distance 0).
+    fn backward_shift(&mut self, mut prev: usize) {
+        let cap = self.buckets.len();
+        loop {
+            let next = (prev + 1) % cap;
+            let should_shift = match &self.buckets[next] {
+                None => false,
+                Some((nhash, _, _)) => {
+                    let nideal = (*nhash % cap as u64) as usize;
+                    nideal != next
+                }
+            };
+            if !should_shift {
+                break;
+            }
+            self.buckets[prev] = self.buckets[next].take();
+            prev = next;
+        }
+    }
+
+    /// Reserves capacity for at least `additional` more elements, rehashing the
+    /// table once if needed.
+    pub fn reserve(&mut self, additional: usize) {
+        self.try_reserve(additional).expect("capacity overflow");
+    }
+
+    /// Like [`reserve`](Self::reserve), but returns an error instead of panicking
+    /// if the new capacity overflows `usize` or the allocation fails.
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        let required = self.items.saturating_add(additional);
+        let target_size = buckets_for_capacity(required);
+        if target_size <= self.buckets.len() {
+            return Ok(());
+        }
+
+        let mut new_buckets: Vec<Slot<K, V>> = Vec::new();
+        new_buckets.try_reserve_exact(target_size)?;
+        new_buckets.extend((0..target_size).map(|_| None));
+        for (hash, key, value) in mem::take(&mut self.buckets).into_iter().flatten() {
+            robin_hood_insert(&mut new_buckets, hash, key, value);
+        }
+        self.buckets = new_buckets;
+        Ok(())
+    }
+
+    fn resize(&mut self) {
+        let target_size = match self.buckets.len() {
+            0 => 1,
+            n => 2 * n,
+        };
+
+        let mut new_buckets = Vec::with_capacity(target_size);
+        new_buckets.extend((0..target_size).map(|_| None));
+        for (hash, key, value) in mem::take(&mut self.buckets).into_iter().flatten() {
+            robin_hood_insert(&mut new_buckets, hash, key, value);
+        }
+        self.buckets = new_buckets;
+    }
+}
+
+impl<'a, K, V> Iterator for HashMapIter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(slot) = self.buckets.get(self.pos) {
+            self.pos += 1;
+            if let Some((_, key, value)) = slot {
+                return Some((key, value));
+            }
+        }
+        None
+    }
+}
+
+impl<'a,K,V,S> IntoIterator for &'a HashMap<K,V,S> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = HashMapIter<'a, K, V>;
+    fn into_iter(self) -> Self::IntoIter {
+        HashMapIter {
+            buckets: &self.buckets,
+            pos: 0,
+        }
+    }
+}
+
+impl<'a, K, V> Iterator for HashMapIterMut<'a, K, V> {
+    type Item = (&'a K, &'a mut V);
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let buckets = mem::take(&mut self.buckets);
+            let (first, rest) = buckets.split_first_mut()?;
+            self.buckets = rest;
+            if let Some((_, key, value)) = first {
+                return Some((key, value));
+            }
+        }
+    }
+}
+
+impl<K, V> Iterator for HashMapIntoIter<K, V> {
+    type Item = (K, V);
+    fn next(&mut self) -> Option<Self::Item> {
+        self.buckets.by_ref().flatten().next().map(|(_, key, value)| (key, value))
+    }
+}
+
+impl<'a, K, V> Iterator for HashMapKeys<'a, K, V> {
+    type Item = &'a K;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(key, _)| key)
+    }
+}
+
+impl<'a, K, V> Iterator for HashMapValues<'a, K, V> {
+    type Item = &'a V;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(_, value)| value)
+    }
+}
+
+impl<'a, K, V> Iterator for HashMapValuesMut<'a, K, V> {
+    type Item = &'a mut V;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(_, value)| value)
+    }
+}
+
+impl<K, V, S> IntoIterator for HashMap<K, V, S> {
+    type Item = (K, V);
+    type IntoIter = HashMapIntoIter<K, V>;
+    fn into_iter(self) -> Self::IntoIter {
+        HashMapIntoIter { buckets: self.buckets.into_iter() }
+    }
+}
+
+impl<K, V, S> FromIterator<(K, V)> for HashMap<K, V, S>
+where
+    K: Hash + Eq,
+    S: BuildHasher + Default,
+{
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let iter = iter.into_iter();
+        let mut map = HashMap::with_capacity_and_hasher(iter.size_hint().0, S::default());
+        map.extend(iter);
+        map
+    }
+}
+
+impl<K, V, S> Extend<(K, V)> for HashMap<K, V, S>
+where
+    K: Hash + Eq,
+    S: BuildHasher,
+{
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty() {
+        let map = HashMap::<u32, u32>::new();
+        assert_eq!(map.is_empty(), true);
+        assert_eq!(map.len(), 0);
+    }
+
+    #[test]
+    fn insert() {
+        let mut map = HashMap::new();
+        map.insert("hello", 42);
+        assert_eq!(map.get(&"hello"), Some(&42));
+    }
+
+    #[test]
+    fn get() {
+        let mut map = HashMap::new();
+        assert_eq!(map.get("hello"), None);
+        map.insert("hello", 42);
+        assert_eq!(map.get(&"hello"), Some(&42));
+    }
+
+    #[test]
+    fn contains_key() {
+        let mut map = HashMap::new();
+        assert_eq!(map.contains_key("hello"), false);
+        map.insert("hello".to_string(), 42);
+        assert_eq!(map.contains_key("hello"), true);
+    }
+
+    #[test]
+    fn borrowed_lookup() {
+        let mut map = HashMap::new();
+        map.insert("hello".to_string(), 42);
+        assert_eq!(map.get("hello"), Some(&42));
+        assert_eq!(map.remove("hello"), Some(42));
+        assert!(!map.contains_key("hello"));
+    }
+
+    #[test]
+    fn remove() {
+        let mut map = HashMap::new();
+        map.insert("hello", 42);
+        assert_eq!(map.remove(&"hello"), Some(42));
+        assert_eq!(map.get(&"hello"), None);
+    }
+
+    #[test]
+    fn remove_then_reinsert_is_found() {
+        let mut map = HashMap::new();
+        for i in 0..32 {
+            map.insert(i, i * 10);
+        }
+        for i in (0..32).step_by(2) {
+            assert_eq!(map.remove(&i), Some(i * 10));
+        }
+        for i in 0..32 {
+            let expected = i * 10;
+            assert_eq!(map.get(&i), if i % 2 == 0 { None } else { Some(&expected) });
+        }
+        map.insert(4, 400);
+        assert_eq!(map.get(&4), Some(&400));
+    }
+
+    #[test]
+    fn iterator() {
+        let mut map = HashMap::<String, String>::new();
+        let books = vec![
+            ("Book1", "Review1"),
+            ("Book2", "Review2")
+        ];
+
+        for (title, review) in &books {
+           map.insert(title.to_string(), review.to_string());
+        }
+
+        let mut books2: Vec<(&String, &String)> = (&map).into_iter().collect();
+        books2.sort();
+
+        let mut expected: Vec<(String, String)> = books
+            .iter()
+            .map(|(title, review)| (title.to_string(), review.to_string()))
+            .collect();
+        expected.sort();
+
+        for i in 0..expected.len() {
+            assert_eq!(expected[i].0, *books2[i].0);
+            assert_eq!(expected[i].1, *books2[i].1);
+        }
+    }
+
+    #[test]
+    fn entry_or_insert() {
+        let mut map = HashMap::new();
+        *map.entry("hello").or_insert(0) += 1;
+        *map.entry("hello").or_insert(0) += 1;
+        assert_eq!(map.get(&"hello"), Some(&2));
+    }
+
+    #[test]
+    fn entry_and_modify() {
+        let mut map = HashMap::new();
+        map.entry("hello").and_modify(|v| *v += 1).or_insert(42);
+        assert_eq!(map.get(&"hello"), Some(&42));
+        map.entry("hello").and_modify(|v| *v += 1).or_insert(42);
+        assert_eq!(map.get(&"hello"), Some(&43));
+    }
+
+    #[test]
+    fn with_hasher_is_usable() {
+        let mut map = HashMap::with_hasher(RandomState::new());
+        map.insert("hello", 42);
+        assert_eq!(map.get(&"hello"), Some(&42));
+    }
+
+    #[test]
+    fn with_capacity_preallocates() {
+        let map = HashMap::<u32, u32>::with_capacity(10);
+        assert!(map.capacity() >= 10);
+    }
+
+    #[test]
+    fn with_capacity_honors_small_capacities() {
+        assert!(HashMap::<u32, u32>::with_capacity(1).capacity() >= 1);
+        assert!(HashMap::<u32, u32>::with_capacity(2).capacity() >= 2);
+    }
+
+    #[test]
+    fn reserve_grows_capacity_without_losing_items() {
+        let mut map = HashMap::new();
+        map.insert("hello", 42);
+        map.reserve(100);
+        assert!(map.capacity() >= 100);
+        assert_eq!(map.get(&"hello"), Some(&42));
+    }
+
+    #[test]
+    fn try_reserve_reports_overflow() {
+        let mut map = HashMap::<u32, u32>::new();
+        assert!(map.try_reserve(usize::MAX).is_err());
+    }
+
+    #[test]
+    fn iter_mut_updates_values_in_place() {
+        let mut map = HashMap::new();
+        map.insert("a", 1);
+        map.insert("b", 2);
+        for (_, value) in map.iter_mut() {
+            *value *= 10;
+        }
+        let mut values: Vec<&i32> = map.values().collect();
+        values.sort();
+        assert_eq!(values, vec![&10, &20]);
+    }
+
+    #[test]
+    fn keys_and_values() {
+        let mut map = HashMap::new();
+        map.insert("a", 1);
+        map.insert("b", 2);
+
+        let mut keys: Vec<&&str> = map.keys().collect();
+        keys.sort();
+        assert_eq!(keys, vec![&"a", &"b"]);
+
+        let mut values: Vec<&i32> = map.values().collect();
+        values.sort();
+        assert_eq!(values, vec![&1, &2]);
+    }
+
+    #[test]
+    fn values_mut() {
+        let mut map = HashMap::new();
+        map.insert("a", 1);
+        for value in map.values_mut() {
+            *value += 100;
+        }
+        assert_eq!(map.get(&"a"), Some(&101));
+    }
+
+    #[test]
+    fn drain_empties_map_while_yielding_entries() {
+        let mut map = HashMap::new();
+        map.insert("a", 1);
+        map.insert("b", 2);
+
+        let mut drained: Vec<(&str, i32)> = map.drain().collect();
+        drained.sort();
+        assert_eq!(drained, vec![("a", 1), ("b", 2)]);
+        assert!(map.is_empty());
+        assert_eq!(map.get(&"a"), None);
+    }
+
+    #[test]
+    fn owned_into_iter_yields_all_entries() {
+        let mut map = HashMap::new();
+        map.insert("a", 1);
+        map.insert("b", 2);
+
+        let mut entries: Vec<(&str, i32)> = map.into_iter().collect();
+        entries.sort();
+        assert_eq!(entries, vec![("a", 1), ("b", 2)]);
+    }
+
+    #[test]
+    fn from_iterator_collects_pairs() {
+        let map: HashMap<&str, i32> = [("a", 1), ("b", 2)].into_iter().collect();
+        assert_eq!(map.get(&"a"), Some(&1));
+        assert_eq!(map.get(&"b"), Some(&2));
+        assert_eq!(map.len(), 2);
+    }
+
+    #[test]
+    fn extend_inserts_all_pairs() {
+        let mut map = HashMap::new();
+        map.insert("a", 1);
+        map.extend([("b", 2), ("c", 3)]);
+        assert_eq!(map.len(), 3);
+        assert_eq!(map.get(&"c"), Some(&3));
+    }
+}
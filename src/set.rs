@@ -0,0 +1,271 @@
+use std::borrow::Borrow;
+use std::collections::hash_map::RandomState;
+use std::collections::TryReserveError;
+use std::hash::{BuildHasher, Hash};
+use std::iter::Chain;
+
+use crate::map::{HashMap, HashMapIter};
+
+/// A hash set implemented as a [`HashMap`] whose values are all `()`.
+#[derive(Debug)]
+pub struct HashSet<T, S = RandomState> {
+    map: HashMap<T, (), S>,
+}
+
+impl<T> HashSet<T, RandomState> {
+    pub fn new() -> Self {
+        HashSet { map: HashMap::new() }
+    }
+
+    /// Creates an empty `HashSet` with at least the specified capacity.
+    pub fn with_capacity(capacity: usize) -> Self {
+        HashSet { map: HashMap::with_capacity(capacity) }
+    }
+}
+
+impl<T> Default for HashSet<T, RandomState> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, S> HashSet<T, S> {
+    /// Creates an empty `HashSet` which will use the given hash builder to hash values.
+    pub fn with_hasher(hash_builder: S) -> Self {
+        HashSet { map: HashMap::with_hasher(hash_builder) }
+    }
+
+    /// Creates an empty `HashSet` with at least the specified capacity, using the
+    /// given hash builder to hash values.
+    pub fn with_capacity_and_hasher(capacity: usize, hash_builder: S) -> Self {
+        HashSet { map: HashMap::with_capacity_and_hasher(capacity, hash_builder) }
+    }
+
+    /// Returns a lower bound on the number of elements the set can hold without
+    /// reallocating.
+    pub fn capacity(&self) -> usize {
+        self.map.capacity()
+    }
+
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+}
+
+impl<T, S> HashSet<T, S>
+where
+    T: Hash + Eq,
+    S: BuildHasher,
+{
+    /// Adds `value` to the set, returning whether it was newly inserted.
+    pub fn insert(&mut self, value: T) -> bool {
+        self.map.insert(value, ()).is_none()
+    }
+
+    pub fn contains<Q>(&self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.contains_key(value)
+    }
+
+    /// Removes `value` from the set, returning whether it was present.
+    pub fn remove<Q>(&mut self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.remove(value).is_some()
+    }
+
+    /// Reserves capacity for at least `additional` more elements, rehashing the
+    /// table once if needed.
+    pub fn reserve(&mut self, additional: usize) {
+        self.map.reserve(additional);
+    }
+
+    /// Like [`reserve`](Self::reserve), but returns an error instead of panicking
+    /// if the new capacity overflows `usize` or the allocation fails.
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.map.try_reserve(additional)
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.into_iter()
+    }
+
+    /// Returns a lazy iterator over the values present in `self` or `other`, with
+    /// values in `self` yielded before the values unique to `other`.
+    pub fn union<'a>(&'a self, other: &'a HashSet<T, S>) -> Union<'a, T, S> {
+        Union {
+            iter: self.iter().chain(other.difference(self)),
+        }
+    }
+
+    /// Returns a lazy iterator over the values in `self` that are also in `other`.
+    pub fn intersection<'a>(&'a self, other: &'a HashSet<T, S>) -> Intersection<'a, T, S> {
+        Intersection { iter: self.iter(), other }
+    }
+
+    /// Returns a lazy iterator over the values in `self` that are not in `other`.
+    pub fn difference<'a>(&'a self, other: &'a HashSet<T, S>) -> Difference<'a, T, S> {
+        Difference { iter: self.iter(), other }
+    }
+
+    /// Returns a lazy iterator over the values in `self` or `other`, but not both.
+    pub fn symmetric_difference<'a>(
+        &'a self,
+        other: &'a HashSet<T, S>,
+    ) -> SymmetricDifference<'a, T, S> {
+        SymmetricDifference {
+            iter: self.difference(other).chain(other.difference(self)),
+        }
+    }
+}
+
+pub struct Iter<'a, T> {
+    inner: HashMapIter<'a, T, ()>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(value, _)| value)
+    }
+}
+
+impl<'a, T, S> IntoIterator for &'a HashSet<T, S> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        Iter { inner: (&self.map).into_iter() }
+    }
+}
+
+pub struct Intersection<'a, T, S> {
+    iter: Iter<'a, T>,
+    other: &'a HashSet<T, S>,
+}
+
+impl<'a, T, S> Iterator for Intersection<'a, T, S>
+where
+    T: Hash + Eq,
+    S: BuildHasher,
+{
+    type Item = &'a T;
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let value = self.iter.next()?;
+            if self.other.contains(value) {
+                return Some(value);
+            }
+        }
+    }
+}
+
+pub struct Difference<'a, T, S> {
+    iter: Iter<'a, T>,
+    other: &'a HashSet<T, S>,
+}
+
+impl<'a, T, S> Iterator for Difference<'a, T, S>
+where
+    T: Hash + Eq,
+    S: BuildHasher,
+{
+    type Item = &'a T;
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let value = self.iter.next()?;
+            if !self.other.contains(value) {
+                return Some(value);
+            }
+        }
+    }
+}
+
+pub struct SymmetricDifference<'a, T, S> {
+    iter: Chain<Difference<'a, T, S>, Difference<'a, T, S>>,
+}
+
+impl<'a, T, S> Iterator for SymmetricDifference<'a, T, S>
+where
+    T: Hash + Eq,
+    S: BuildHasher,
+{
+    type Item = &'a T;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+}
+
+pub struct Union<'a, T, S> {
+    iter: Chain<Iter<'a, T>, Difference<'a, T, S>>,
+}
+
+impl<'a, T, S> Iterator for Union<'a, T, S>
+where
+    T: Hash + Eq,
+    S: BuildHasher,
+{
+    type Item = &'a T;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_and_contains() {
+        let mut set = HashSet::new();
+        assert!(set.insert("a"));
+        assert!(!set.insert("a"));
+        assert!(set.contains("a"));
+        assert_eq!(set.len(), 1);
+    }
+
+    #[test]
+    fn remove() {
+        let mut set = HashSet::new();
+        set.insert("a");
+        assert!(set.remove("a"));
+        assert!(!set.remove("a"));
+        assert!(!set.contains("a"));
+    }
+
+    #[test]
+    fn set_algebra() {
+        let a: HashSet<i32> = [1, 2, 3].into_iter().fold(HashSet::new(), |mut s, v| {
+            s.insert(v);
+            s
+        });
+        let b: HashSet<i32> = [2, 3, 4].into_iter().fold(HashSet::new(), |mut s, v| {
+            s.insert(v);
+            s
+        });
+
+        let mut union: Vec<i32> = a.union(&b).copied().collect();
+        union.sort();
+        assert_eq!(union, vec![1, 2, 3, 4]);
+
+        let mut intersection: Vec<i32> = a.intersection(&b).copied().collect();
+        intersection.sort();
+        assert_eq!(intersection, vec![2, 3]);
+
+        let mut difference: Vec<i32> = a.difference(&b).copied().collect();
+        difference.sort();
+        assert_eq!(difference, vec![1]);
+
+        let mut symmetric_difference: Vec<i32> = a.symmetric_difference(&b).copied().collect();
+        symmetric_difference.sort();
+        assert_eq!(symmetric_difference, vec![1, 4]);
+    }
+}